@@ -1,7 +1,27 @@
-use crate::mem::defs::{BlockSize, ALIGNMENT, MAX_MEMBLOCK_SIZE};
-use std::alloc::{alloc, dealloc, Layout};
+use crate::mem::defs::{BlockSize, MAX_MEMBLOCK_SIZE};
+use std::alloc::{alloc, alloc_zeroed, dealloc, AllocError, Allocator, Layout};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::mem::size_of_val;
-use std::ptr::{read, write, NonNull};
+use std::ptr::{copy_nonoverlapping, read, write, write_bytes, NonNull};
+
+/// Whether a [`MemBlock`] resize is allowed to move the backing allocation to a
+/// new address or must keep the original pointer valid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReallocPlacement {
+    /// The block must keep its current pointer; fails if the size class changes.
+    InPlace,
+    /// The block may be reallocated at a new address and its contents copied over.
+    MayMove,
+}
+
+/// Whether the bytes newly exposed by a resize should be left uninitialized or
+/// zeroed out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AllocInit {
+    Uninitialized,
+    Zeroed,
+}
 
 /// A holder for memory block, its pointer in the [`std::alloc::GlobalAlloc`],
 /// the default alignment and its total size.
@@ -12,43 +32,83 @@ use std::ptr::{read, write, NonNull};
 /// have when interacting with it.
 pub struct MemBlock {
     ptr: NonNull<u8>,
+    requested_size: BlockSize,
     size: BlockSize,
     align: usize,
     is_freed: bool,
+    regions: BTreeMap<usize, RegionEntry>,
+}
+
+/// Tracks what has been written into a byte range `[start, end)` of a
+/// [`MemBlock`], keyed by `start` in the owning [`BTreeMap`] so a query can
+/// walk every candidate entry whose `start` precedes the probed range's end
+/// and test `entry.end > probe_start` to find all regions overlapping it.
+#[derive(Debug, Copy, Clone)]
+struct RegionEntry {
+    end: usize,
+    is_quantum: bool,
 }
 
 impl MemBlock {
-    /// create a new memory block with a given size (smaller than [`MAX_MEMBLOCK_SIZE`]
-    /// and power of two), and alignment size (power of two).
+    /// create a new memory block able to hold at least `size` bytes (rounded up
+    /// to the next power of two), and alignment size (power of two). Fails with
+    /// [`MemAllocError::InvalidBlockSize`] if the rounded size exceeds
+    /// [`MAX_MEMBLOCK_SIZE`].
+    ///
+    /// The block's true backing size may be larger than `size`; use
+    /// [`MemBlock::usable_size`] to see the full extent and [`MemBlock::as_slice`]
+    /// to access it.
     pub unsafe fn new(size: BlockSize, align: usize) -> Result<MemBlock, MemAllocError> {
-        // size must not exceed the maximum permitted block size
-        if size > MAX_MEMBLOCK_SIZE {
-            return Err(MemAllocError::InvalidBlockSize);
-        }
+        Self::new_with_init(size, align, AllocInit::Uninitialized)
+    }
 
-        // size must be power of two so a memory block can be properly allocated
-        if !size.is_power_of_two() {
-            return Err(MemAllocError::NotPowerOfTwo)
+    /// Like [`MemBlock::new`], but the entire backing block (including the
+    /// slack above the requested `size`) starts as guaranteed-zero bytes. This
+    /// matters for working-data buffers that must not leak prior heap contents
+    /// when a freshly allocated region is peeked before being written.
+    pub unsafe fn new_zeroed(size: BlockSize, align: usize) -> Result<MemBlock, MemAllocError> {
+        Self::new_with_init(size, align, AllocInit::Zeroed)
+    }
+
+    unsafe fn new_with_init(
+        size: BlockSize,
+        align: usize,
+        init: AllocInit,
+    ) -> Result<MemBlock, MemAllocError> {
+        let usable_size: BlockSize = size.next_power_of_two();
+
+        // usable size must not exceed the maximum permitted block size
+        if usable_size > MAX_MEMBLOCK_SIZE {
+            return Err(MemAllocError::InvalidBlockSize);
         }
 
-        match Self::alloc_memblock(size, align) {
+        match Self::alloc_memblock(usable_size, align, init) {
             Ok(value) => Ok(MemBlock {
                 ptr: value,
-                size,
+                requested_size: size,
+                size: usable_size,
                 align,
                 is_freed: false,
+                regions: BTreeMap::new(),
             }),
             Err(value) => Err(value),
         }
 
     }
 
-    unsafe fn alloc_memblock(size: BlockSize, align: usize) -> Result<NonNull<u8>, MemAllocError> {
+    unsafe fn alloc_memblock(
+        size: BlockSize,
+        align: usize,
+        init: AllocInit,
+    ) -> Result<NonNull<u8>, MemAllocError> {
         let layout: Layout = match Layout::from_size_align(size, align) {
             Ok(layout) => layout,
             Err(_) => return Err(MemAllocError::LayoutError),
         };
-        let ptr: *mut u8 = alloc(layout);
+        let ptr: *mut u8 = match init {
+            AllocInit::Uninitialized => alloc(layout),
+            AllocInit::Zeroed => alloc_zeroed(layout),
+        };
 
         if ptr.is_null() {
             return Err(MemAllocError::NullPointer)
@@ -58,7 +118,7 @@ impl MemBlock {
 
     pub unsafe fn free(&mut self) -> Result<MemAllocSuccess, MemAllocError> {
         if !self.is_freed {
-            let layout: Layout = match Layout::from_size_align(self.size, ALIGNMENT) {
+            let layout: Layout = match Layout::from_size_align(self.size, self.align) {
                 Ok(layout) => layout,
                 Err(_) => return Err(MemAllocError::LayoutError),
             };
@@ -75,12 +135,146 @@ impl MemBlock {
         self.ptr.as_ptr()
     }
 
+    /// The logical size originally requested via [`MemBlock::new`], before
+    /// rounding up to a power of two.
+    pub fn requested_size(&self) -> usize {
+        self.requested_size
+    }
+
+    /// The true backing size of the allocation. This may be larger than
+    /// [`MemBlock::requested_size`] since every block is rounded up to the next
+    /// power of two; that slack can still be written to via [`MemBlock::push`].
+    pub fn usable_size(&self) -> usize {
+        self.size
+    }
+
+    /// A raw pointer over the full backing extent of the block, i.e.
+    /// `usable_size()` bytes starting at [`MemBlock::as_ptr`].
+    pub fn as_slice(&self) -> *const [u8] {
+        std::ptr::slice_from_raw_parts(self.ptr.as_ptr(), self.size)
+    }
+
+    /// Resize the block up to `new_size`, which must be a power of two no smaller
+    /// than the current `size` and no larger than [`MAX_MEMBLOCK_SIZE`].
+    ///
+    /// With [`ReallocPlacement::InPlace`] this always fails with
+    /// [`MemAllocError::CannotResizeInPlace`] whenever `new_size` differs from the
+    /// current size, since `std::alloc` gives no portable guarantee of resizing
+    /// without moving. With [`ReallocPlacement::MayMove`] a fresh block is
+    /// allocated, the live bytes are copied over, and the old block is freed.
+    ///
+    /// With [`AllocInit::Zeroed`] the bytes newly exposed above the old size are
+    /// zeroed; with [`AllocInit::Uninitialized`] they are left as allocated.
+    /// When shrinking no bytes are exposed, so `init` has no effect.
+    pub unsafe fn grow(
+        &mut self,
+        new_size: BlockSize,
+        placement: ReallocPlacement,
+        init: AllocInit,
+    ) -> Result<(), MemAllocError> {
+        if new_size < self.size {
+            return Err(MemAllocError::InvalidBlockSize);
+        }
+
+        self.resize(new_size, placement, init)
+    }
+
+    /// Resize the block down to `new_size`, which must be a power of two no
+    /// larger than the current `size`. See [`MemBlock::grow`] for placement and
+    /// init semantics.
+    pub unsafe fn shrink(
+        &mut self,
+        new_size: BlockSize,
+        placement: ReallocPlacement,
+        init: AllocInit,
+    ) -> Result<(), MemAllocError> {
+        if new_size > self.size {
+            return Err(MemAllocError::InvalidBlockSize);
+        }
+
+        self.resize(new_size, placement, init)
+    }
+
+    unsafe fn resize(
+        &mut self,
+        new_size: BlockSize,
+        placement: ReallocPlacement,
+        init: AllocInit,
+    ) -> Result<(), MemAllocError> {
+        if new_size > MAX_MEMBLOCK_SIZE {
+            return Err(MemAllocError::InvalidBlockSize);
+        }
+
+        if !new_size.is_power_of_two() {
+            return Err(MemAllocError::NotPowerOfTwo);
+        }
+
+        if self.is_freed {
+            return Err(MemAllocError::MemoryAlreadyFreed);
+        }
+
+        if new_size == self.size {
+            return Ok(());
+        }
+
+        if let ReallocPlacement::InPlace = placement {
+            return Err(MemAllocError::CannotResizeInPlace);
+        }
+
+        let old_ptr: NonNull<u8> = self.ptr;
+        let old_size: BlockSize = self.size;
+        let old_layout: Layout = match Layout::from_size_align(old_size, self.align) {
+            Ok(layout) => layout,
+            Err(_) => return Err(MemAllocError::LayoutError),
+        };
+
+        let new_ptr: NonNull<u8> = Self::alloc_memblock(new_size, self.align, AllocInit::Uninitialized)?;
+        let copy_size: usize = old_size.min(new_size);
+        copy_nonoverlapping(old_ptr.as_ptr(), new_ptr.as_ptr(), copy_size);
+
+        if new_size > old_size && init == AllocInit::Zeroed {
+            write_bytes(new_ptr.as_ptr().add(old_size), 0, new_size - old_size);
+        }
+
+        dealloc(old_ptr.as_ptr(), old_layout);
+
+        self.ptr = new_ptr;
+        self.size = new_size;
+
+        Ok(())
+    }
+
+    /// Flags the byte range `[offset, offset + len)` of this block as holding a
+    /// quantum or classical value. [`MemBlock::peek`] refuses to read a region
+    /// marked `is_quantum` (no-cloning), while [`MemBlock::pop`] may still
+    /// consume it since that moves the value out and removes the region entry.
+    pub unsafe fn mark_region(&mut self, offset: usize, len: usize, is_quantum: bool) {
+        self.regions.insert(
+            offset,
+            RegionEntry {
+                end: offset + len,
+                is_quantum,
+            },
+        );
+    }
+
+    /// Finds every tracked region overlapping the byte range `[start, end)`,
+    /// including ones that start before `start` but extend past it (a region
+    /// nested or straddling the probed range is still a hit) and ones nested
+    /// entirely inside it.
+    fn regions_overlapping(&self, start: usize, end: usize) -> impl Iterator<Item = (usize, RegionEntry)> + '_ {
+        self.regions
+            .range(..end)
+            .filter(move |(_, entry)| entry.end > start)
+            .map(|(&region_start, &entry)| (region_start, entry))
+    }
+
     /// Push data `T` to the memory block and returns its pointer position
     pub unsafe fn push<T>(&mut self, data: T) -> Result<usize, MemAllocError> {
         let offset: usize = size_of_val(&data);
         let ptr: *mut T = self.as_ptr().add(offset) as *mut T;
 
-        if (ptr as usize) <= (self.as_ptr().add(self.size) as usize) {
+        if (ptr as usize) <= (self.as_ptr().add(self.usable_size()) as usize) {
             write(ptr, data);
             Ok(ptr as usize)
         } else {
@@ -105,6 +299,18 @@ impl MemBlock {
         // get the new pointer position subtracted from the data memory space
         let new_cursor_ptr: usize = (cursor_ptr as *const u8).sub(data_size) as usize;
 
+        // popping consumes/moves the value out, so it's fine even over a region
+        // flagged `is_quantum` (no duplicate read survives); drop every region
+        // entry the popped bytes touched
+        let probe: usize = cursor_ptr - self.as_ptr() as usize;
+        let overlapping_starts: Vec<usize> = self
+            .regions_overlapping(probe, probe + data_size)
+            .map(|(start, _)| start)
+            .collect();
+        for start in overlapping_starts {
+            self.regions.remove(&start);
+        }
+
         // the pointer handler (that called this very function) now has an updated
         // pointer to use as its new cursor, for instance.
         Ok((data, data_size, new_cursor_ptr))
@@ -114,10 +320,23 @@ impl MemBlock {
     /// it should handle the right pointer position (the last written data, for a stack
     /// memory API, for instance). The pointer is not updated since it's just peeking
     /// into the memory.
-    pub unsafe fn peek<T>(&mut self, ptr: usize) -> T {
-        // let mem_ptr: *const T = self.as_ptr().add(ptr) as *const T;
-        // read(mem_ptr)
-        read(ptr as *const T)
+    ///
+    /// Fails with [`MemAllocError::NoCloning`] when the `size_of::<T>()` bytes
+    /// read starting at `ptr` touch any region marked `is_quantum` via
+    /// [`MemBlock::mark_region`], since peeking would duplicate a qubit's
+    /// value rather than consuming it.
+    pub unsafe fn peek<T>(&mut self, ptr: usize) -> Result<T, MemAllocError> {
+        let probe: usize = ptr - self.as_ptr() as usize;
+        let len: usize = std::mem::size_of::<T>();
+
+        if self
+            .regions_overlapping(probe, probe + len)
+            .any(|(_, entry)| entry.is_quantum)
+        {
+            return Err(MemAllocError::NoCloning);
+        }
+
+        Ok(read(ptr as *const T))
     }
 }
 
@@ -131,12 +350,14 @@ impl Drop for MemBlock {
 
 #[derive(Debug)]
 pub enum MemAllocError {
+    CannotResizeInPlace,
     EmptyMemory,
     InvalidBlockSize,
     InvalidAlignment,
     LayoutError,
     MemoryAlreadyFreed,
     MemoryOverflow,
+    NoCloning,
     NotEnoughMemory,
     NotPowerOfTwo,
     NullPointer,
@@ -149,6 +370,114 @@ pub enum MemAllocSuccess {
     DataPushedToMemory,
 }
 
+/// Bump/pool allocator backed by one or more [`MemBlock`]s, exposed to the
+/// standard collection ecosystem via the unstable `core::alloc::Allocator`
+/// trait (requires `#![feature(allocator_api)]` at the crate root).
+///
+/// Allocations are carved out of the current block with a bump cursor; when
+/// the block is exhausted a new one is allocated, sized to the next power of
+/// two that fits the request. Each call hands back the whole remainder of its
+/// block (not just the requested size) so the overallocation is visible to
+/// the caller, which means the cursor jumps to the end of the block after
+/// every allocation. [`Allocator::deallocate`] only reclaims the single
+/// most-recent allocation (LIFO), mirroring [`MemBlock::pop`]'s cursor rewind;
+/// everything else is freed in bulk when the blocks themselves drop.
+pub struct MemBlockAllocator {
+    state: RefCell<BumpState>,
+}
+
+struct BumpState {
+    blocks: Vec<MemBlock>,
+    cursor: usize,
+    last_alloc: Option<(usize, usize)>,
+}
+
+impl MemBlockAllocator {
+    pub fn new() -> Self {
+        MemBlockAllocator {
+            state: RefCell::new(BumpState {
+                blocks: Vec::new(),
+                cursor: 0,
+                last_alloc: None,
+            }),
+        }
+    }
+}
+
+impl Default for MemBlockAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+unsafe impl Allocator for MemBlockAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            let ptr = NonNull::new(layout.align() as *mut u8).ok_or(AllocError)?;
+            return Ok(NonNull::slice_from_raw_parts(ptr, 0));
+        }
+
+        let mut state = self.state.borrow_mut();
+
+        if let Some(block) = state.blocks.last() {
+            let base = block.as_ptr() as usize;
+            let aligned_offset = align_up(base + state.cursor, layout.align()) - base;
+
+            if aligned_offset + layout.size() <= block.usable_size() {
+                let ptr = unsafe {
+                    NonNull::new_unchecked(block.as_ptr().add(aligned_offset) as *mut u8)
+                };
+                // hand the caller the whole remainder of the block, not just the
+                // requested size, so the overallocation is visible to them
+                let actual_len = block.usable_size() - aligned_offset;
+                state.cursor = aligned_offset + actual_len;
+                state.last_alloc = Some((aligned_offset, layout.size()));
+                return Ok(NonNull::slice_from_raw_parts(ptr, actual_len));
+            }
+        }
+
+        // current block (if any) is exhausted: grab a fresh one sized to the request
+        let new_block_size = layout.size().max(layout.align()).next_power_of_two();
+        let new_block = unsafe { MemBlock::new(new_block_size, layout.align()) }
+            .map_err(|_| AllocError)?;
+        let ptr = unsafe { NonNull::new_unchecked(new_block.as_ptr() as *mut u8) };
+        let actual_len = new_block.usable_size();
+
+        state.blocks.push(new_block);
+        state.cursor = actual_len;
+        state.last_alloc = Some((0, layout.size()));
+
+        Ok(NonNull::slice_from_raw_parts(ptr, actual_len))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() == 0 {
+            return;
+        }
+
+        let mut state = self.state.borrow_mut();
+
+        let reclaims_last = match (state.blocks.last(), state.last_alloc) {
+            (Some(block), Some((offset, len))) => {
+                len == layout.size()
+                    && std::ptr::eq(block.as_ptr().add(offset), ptr.as_ptr() as *const u8)
+            }
+            _ => false,
+        };
+
+        if reclaims_last {
+            let (offset, _) = state.last_alloc.take().unwrap();
+            state.cursor = offset;
+        }
+        // otherwise: the bump strategy only reclaims the most recent allocation;
+        // everything else is freed in bulk when the backing blocks drop
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,7 +502,7 @@ mod tests {
             );
             println!("   - [x] push data, received ptr: {:}", data_ptr);
 
-            let retrieved_data = memblock.peek::<u64>(data_ptr);
+            let retrieved_data = memblock.peek::<u64>(data_ptr).unwrap();
             assert_eq!(retrieved_data, 1u64);
             println!("   - [x] peek data: {:}", retrieved_data);
 
@@ -200,6 +529,149 @@ mod tests {
     #[test]
     fn test_many_memblock_operations() {}
 
+    /// test that a non-power-of-two request is rounded up and the requested
+    /// size is tracked separately from the usable (backing) size
+    #[test]
+    fn test_memblock_usable_size() {
+        unsafe {
+            let memblock = MemBlock::new(48usize, 8usize).unwrap();
+            assert_eq!(memblock.requested_size(), 48usize);
+            assert_eq!(memblock.usable_size(), 64usize);
+            assert_eq!(memblock.as_slice().len(), 64usize);
+        }
+    }
+
+    /// test that `new_zeroed` hands back an all-zero backing block, slack
+    /// included, unlike the default uninitialized `new`
+    #[test]
+    fn test_memblock_new_zeroed() {
+        unsafe {
+            let memblock = MemBlock::new_zeroed(48usize, 8usize).unwrap();
+            let bytes = &*memblock.as_slice();
+            assert_eq!(memblock.usable_size(), 64usize);
+            assert!(bytes.iter().all(|&b| b == 0));
+        }
+    }
+
+    /// test that a region marked quantum refuses `peek` with `NoCloning` but
+    /// still allows `pop`, after which the region is forgotten
+    #[test]
+    fn test_memblock_no_cloning() {
+        unsafe {
+            let mut memblock = MemBlock::new(64usize, 8usize).unwrap();
+
+            let data_ptr = memblock.push(7u64).unwrap();
+            let offset = data_ptr - memblock.as_ptr() as usize;
+            memblock.mark_region(offset, size_of_val(&7u64), true);
+
+            let err = memblock.peek::<u64>(data_ptr).unwrap_err();
+            assert!(matches!(err, MemAllocError::NoCloning));
+
+            let (d, _, _) = memblock.pop::<u64>(data_ptr).unwrap();
+            assert_eq!(d, 7u64);
+
+            // the region entry is gone now, so a classical re-write at the same
+            // offset can be peeked freely
+            memblock.push(9u64).unwrap();
+            assert_eq!(memblock.peek::<u64>(data_ptr).unwrap(), 9u64);
+        }
+    }
+
+    /// test that `peek` catches a quantum region even when a later, narrower
+    /// entry with a higher start is nested inside it, and even when only the
+    /// tail of the read overlaps the quantum range
+    #[test]
+    fn test_memblock_no_cloning_overlap_holes() {
+        unsafe {
+            let mut memblock = MemBlock::new(128usize, 8usize).unwrap();
+            let base = memblock.as_ptr() as usize;
+
+            // a wide quantum region, then a narrower classical one nested
+            // inside it with a higher start offset
+            memblock.mark_region(0, 100, true);
+            memblock.mark_region(50, 10, false);
+
+            // probing at offset 70 isn't covered by the `[50, 60)` entry, but
+            // it is still inside the outer `[0, 100)` quantum region
+            let err = memblock.peek::<u8>(base + 70).unwrap_err();
+            assert!(matches!(err, MemAllocError::NoCloning));
+
+            // a read that starts in a classical region but extends into a
+            // quantum one must also be rejected
+            memblock.mark_region(104, 8, false);
+            memblock.mark_region(108, 8, true);
+            let err = memblock.peek::<u64>(base + 104).unwrap_err();
+            assert!(matches!(err, MemAllocError::NoCloning));
+        }
+    }
+
+    /// test that the bump allocator carves aligned regions out of a block,
+    /// grows into a fresh block once exhausted, and only reclaims the most
+    /// recent allocation on deallocate
+    #[test]
+    fn test_memblock_allocator_bump_and_reclaim() {
+        let allocator = MemBlockAllocator::new();
+
+        // a 24-byte request rounds up to a 32-byte block, and the whole
+        // remainder is handed back so the overallocation is visible
+        let layout_a = Layout::from_size_align(24, 8).unwrap();
+        let a = allocator.allocate(layout_a).unwrap();
+        assert_eq!(a.len(), 32);
+
+        // block `a` already claimed its entire backing block, so `b` lands in
+        // a fresh one
+        let layout_b = Layout::from_size_align(8, 8).unwrap();
+        let b = allocator.allocate(layout_b).unwrap();
+        assert_eq!(b.len(), 8);
+        assert!(!std::ptr::eq(a.as_ptr() as *const u8, b.as_ptr() as *const u8));
+
+        let b_addr = b.as_ptr() as *const u8 as usize;
+
+        unsafe {
+            allocator.deallocate(NonNull::new(b.as_ptr() as *mut u8).unwrap(), layout_b);
+        }
+
+        // LIFO reclaim lets the next same-size allocation reuse `b`'s block
+        let c = allocator.allocate(layout_b).unwrap();
+        let c_addr = c.as_ptr() as *const u8 as usize;
+        assert_eq!(c_addr, b_addr);
+        assert_eq!(c.len(), 8);
+
+        let huge = allocator
+            .allocate(Layout::from_size_align(MAX_MEMBLOCK_SIZE, 8).unwrap())
+            .unwrap();
+        assert!(huge.len() >= MAX_MEMBLOCK_SIZE);
+    }
+
+    /// test growing and shrinking a memblock, in-place rejection and moved copies
+    #[test]
+    fn test_memblock_grow_shrink() {
+        unsafe {
+            let mut memblock = MemBlock::new(8usize, 8usize).unwrap();
+
+            let err = memblock
+                .grow(16usize, ReallocPlacement::InPlace, AllocInit::Uninitialized)
+                .unwrap_err();
+            assert!(matches!(err, MemAllocError::CannotResizeInPlace));
+            assert_eq!(memblock.size, 8usize);
+
+            memblock
+                .grow(16usize, ReallocPlacement::MayMove, AllocInit::Zeroed)
+                .unwrap();
+            assert_eq!(memblock.size, 16usize);
+
+            memblock
+                .shrink(8usize, ReallocPlacement::MayMove, AllocInit::Uninitialized)
+                .unwrap();
+            assert_eq!(memblock.size, 8usize);
+
+            let err = memblock
+                .grow(4usize, ReallocPlacement::MayMove, AllocInit::Uninitialized)
+                .unwrap_err();
+            assert!(matches!(err, MemAllocError::InvalidBlockSize));
+        }
+    }
+
     #[test]
     fn test_struct_memblock_operations() {
         unsafe {
@@ -222,7 +694,7 @@ mod tests {
             let data_ptr = memblock.push(data_struct.clone()).unwrap();
             println!("   - [x] input data: {:?}", data_struct);
             println!("   - [x] push data, received ptr: {:}", data_ptr);
-            let retrieved_data = memblock.peek::<TestStruct>(data_ptr);
+            let retrieved_data = memblock.peek::<TestStruct>(data_ptr).unwrap();
             assert_eq!(retrieved_data, data_struct);
             println!("   - [x] peek data: {:?}", retrieved_data);
 